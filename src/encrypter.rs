@@ -2,15 +2,16 @@
 use crate::aes::AesVecBuffer;
 use ::aes::cipher;
 use ::aes::cipher::generic_array::GenericArray;
-use aes::AesCipher;
-use aes_gcm_siv::AesGcmSiv;
+use aes::{AeadAlgorithm, AesCipher};
 use aes_gcm_siv::{
     aead::{AeadInPlace, Buffer, KeyInit, OsRng},
-    Aes256GcmSiv, Nonce,
+    Aes128GcmSiv, Aes256GcmSiv, Nonce,
 };
+use aes_gcm_siv::aead::rand_core::RngCore;
+use chacha20poly1305::ChaCha20Poly1305;
 use std::fmt::Debug;
-use std::io::Read;
 use std::marker::PhantomData;
+use zeroize::Zeroize;
 
 pub struct Encrypter<EncryptionProvider> {
     config: EncrypterConfig,
@@ -27,28 +28,76 @@ impl<EP> Encrypter<EP> {
 }
 
 pub trait Encryptable<EncryptionProvider> {
-    fn encrypt(&mut self, input: &str, provider: &mut EncryptionProvider) -> String;
-    fn decrypt(&mut self, input: &str) -> String;
+    fn encrypt(&mut self, input: &str, aad: &[u8], provider: &mut EncryptionProvider) -> String;
+    fn decrypt(&mut self, input: &str, aad: &[u8]) -> crate::error::Result<String>;
 }
 
 impl<EncryptionProvider> Encryptable<EncryptionProvider> for Encrypter<EncryptionProvider>
 where
     EncryptionProvider: AesEncryptionProviderTrait,
 {
-    fn encrypt(&mut self, input: &str, provider: &mut EncryptionProvider) -> String {
+    fn encrypt(&mut self, input: &str, aad: &[u8], provider: &mut EncryptionProvider) -> String {
         let config = &self.config;
         let cipher = &config.cipher;
         let plain_text = input;
 
-        provider.perform_encryption(plain_text, cipher)
+        provider.perform_encryption(plain_text, aad, cipher)
     }
-    fn decrypt(&mut self, input: &str) -> String {
-        "".to_string()
+
+    fn decrypt(&mut self, input: &str, aad: &[u8]) -> crate::error::Result<String> {
+        let config = &self.config;
+        let cipher = &config.cipher.cipher;
+
+        let bytes = hex::decode(input).map_err(|err| {
+            crate::DefaultError::ErrorMessage(format!(
+                "[{}] Failed to hex-decode ciphertext due to {}.",
+                env!("CARGO_CRATE_NAME"),
+                err
+            ))
+        })?;
+
+        // Every ciphertext carries the nonce `encrypt` generated for it, since that nonce is
+        // never persisted on `config` (see `AesEncryptionProvide::perform_encryption`).
+        if bytes.len() < 12 {
+            return Err(crate::DefaultError::ErrorMessage(format!(
+                "[{}] Ciphertext is missing its nonce prefix.",
+                env!("CARGO_CRATE_NAME")
+            )));
+        }
+        let (nonce_bytes, ciphertext) = bytes.split_at(12);
+        let nonce: &GenericArray<u8, cipher::consts::U12> = GenericArray::from_slice(nonce_bytes);
+
+        let mut buffer = AesVecBuffer::<()>::new();
+        buffer.extend_from_slice(ciphertext).unwrap();
+
+        cipher
+            .decrypt_in_place(nonce, aad, &mut buffer)
+            .map_err(|err| {
+                crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Failed to decrypt due to {}.",
+                    env!("CARGO_CRATE_NAME"),
+                    err
+                ))
+            })?;
+
+        let plain_text = String::from_utf8(buffer.inner().to_vec()).map_err(|err| {
+            crate::DefaultError::ErrorMessage(format!(
+                "[{}] Decrypted plaintext is not valid UTF-8: {}.",
+                env!("CARGO_CRATE_NAME"),
+                err
+            ))
+        });
+
+        // `buffer` now holds the recovered secret plaintext; wipe it once we're done reading it
+        // so no copy lingers in freed memory.
+        buffer.zeroize();
+
+        plain_text
     }
 }
 
 pub trait AesEncryptionProviderTrait {
-    fn perform_encryption(&mut self, plain_text: &str, cipher: &AesCipher) -> String;
+    fn perform_encryption(&mut self, plain_text: &str, aad: &[u8], cipher: &AesCipher) -> String;
 }
 
 pub struct AesEncryptionProvide<'a> {
@@ -62,17 +111,28 @@ impl<'a> AesEncryptionProvide<'a> {
         }
     }
 
-    /// Hex encoded ciphertext
-    fn ciphertext_hex(&mut self) -> String {
-        let text = hex::encode(self.buffer.inner().to_vec());
+    /// Hex encodes `nonce` followed by the buffered ciphertext, so the nonce this call used
+    /// travels with the ciphertext instead of needing to be remembered out of band.
+    fn ciphertext_hex(&mut self, nonce: &GenericArray<u8, cipher::consts::U12>) -> String {
+        let mut bytes = nonce.to_vec();
+        bytes.extend_from_slice(&self.buffer.inner());
 
-        text
+        hex::encode(bytes)
     }
 }
 
 impl<'a> AesEncryptionProviderTrait for AesEncryptionProvide<'a> {
-    fn perform_encryption(&mut self, plain_text: &str, cipher: &AesCipher) -> String {
-        let (cipher, nonce) = (&cipher.cipher, &cipher.nonce);
+    fn perform_encryption(&mut self, plain_text: &str, aad: &[u8], cipher: &AesCipher) -> String {
+        let cipher = &cipher.cipher;
+
+        // A fresh nonce is generated for every call rather than stored on `AesCipher`: `encrypt`
+        // takes `&mut self` so nothing stops a caller from invoking it repeatedly on one
+        // `Encrypter`, and a classical AEAD like ChaCha20Poly1305 (unlike the GCM-SIV variants)
+        // loses all security under (key, nonce) reuse. Embedding the nonce in the output is what
+        // makes that safe regardless of which algorithm is selected.
+        let mut nonce_bytes = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce: GenericArray<u8, cipher::consts::U12> = *Nonce::from_slice(&nonce_bytes);
 
         // Note: buffer needs 16-bytes overhead for auth tag tag
         self.buffer
@@ -80,7 +140,7 @@ impl<'a> AesEncryptionProviderTrait for AesEncryptionProvide<'a> {
             .unwrap();
 
         cipher
-            .encrypt_in_place(nonce, b"", &mut self.buffer)
+            .encrypt_in_place(&nonce, aad, &mut self.buffer)
             .map_err(|err| -> crate::error::Result<()> {
                 let err = format!(
                     "[{}] Failed to encrypt due to {}.",
@@ -91,7 +151,13 @@ impl<'a> AesEncryptionProviderTrait for AesEncryptionProvide<'a> {
             })
             .expect("Encrypt cipher in place");
 
-        self.ciphertext_hex()
+        let ciphertext_hex = self.ciphertext_hex(&nonce);
+
+        // `encrypt_in_place` already overwrote the plaintext bytes with ciphertext, but wipe
+        // the buffer once we're done reading it so no copy of it lingers in freed memory.
+        self.buffer.zeroize();
+
+        ciphertext_hex
     }
 }
 
@@ -113,17 +179,100 @@ mod encryptable {
         let pbkdf_key = hasher
             .pbkdf2_gen("password", "salt", &PBKDF_ROUNDS)
             .unwrap();
-        let pbkdf_key_hex = hex::encode(pbkdf_key);
+        let pbkdf_key_hex = hex::encode(*pbkdf_key);
 
-        let config = EncrypterConfig::new(pbkdf_key_hex);
+        let config = EncrypterConfig::new(pbkdf_key_hex).unwrap();
 
         // Create Encrypter
         let mut provider = AesEncryptionProvide::new();
         let mut enc = super::Encrypter::<AesEncryptionProvide>::new(config);
-        let r = enc.encrypt("secret nuke codes", &mut provider);
+        let r = enc.encrypt("secret nuke codes", b"", &mut provider);
 
         assert_ne!(r, "")
     }
+
+    #[test]
+    fn test_decrypt_round_trip() {
+        const PBKDF_ROUNDS: u32 = 2;
+        let buf = [0u8; crate::hasher::KEY_BUFF_SIZE];
+        let mut buf_boxed = Box::new(buf);
+
+        let hasher =
+            &mut crate::hasher::HashProvider::<crate::hasher::PrfHasher>::new(&mut buf_boxed);
+        let pbkdf_key = hasher
+            .pbkdf2_gen("password", "salt", &PBKDF_ROUNDS)
+            .unwrap();
+        let pbkdf_key_hex = hex::encode(*pbkdf_key);
+
+        let config = EncrypterConfig::new(pbkdf_key_hex).unwrap();
+
+        let mut provider = AesEncryptionProvide::new();
+        let mut enc = super::Encrypter::<AesEncryptionProvide>::new(config);
+
+        let plain_text = "secret nuke codes";
+        let cipher_text = enc.encrypt(plain_text, b"record-42", &mut provider);
+        let recovered = enc.decrypt(&cipher_text, b"record-42").unwrap();
+
+        assert_eq!(recovered, plain_text);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_mismatched_aad() {
+        const PBKDF_ROUNDS: u32 = 2;
+        let buf = [0u8; crate::hasher::KEY_BUFF_SIZE];
+        let mut buf_boxed = Box::new(buf);
+
+        let hasher =
+            &mut crate::hasher::HashProvider::<crate::hasher::PrfHasher>::new(&mut buf_boxed);
+        let pbkdf_key = hasher
+            .pbkdf2_gen("password", "salt", &PBKDF_ROUNDS)
+            .unwrap();
+        let pbkdf_key_hex = hex::encode(*pbkdf_key);
+
+        let config = EncrypterConfig::new(pbkdf_key_hex).unwrap();
+
+        let mut provider = AesEncryptionProvide::new();
+        let mut enc = super::Encrypter::<AesEncryptionProvide>::new(config);
+
+        let cipher_text = enc.encrypt("secret nuke codes", b"record-42", &mut provider);
+
+        assert!(enc.decrypt(&cipher_text, b"record-43").is_err());
+    }
+
+    #[test]
+    fn test_chacha20poly1305_reuses_config_safely_across_multiple_encrypt_calls() {
+        const PBKDF_ROUNDS: u32 = 2;
+        let buf = [0u8; crate::hasher::KEY_BUFF_SIZE];
+        let mut buf_boxed = Box::new(buf);
+
+        let hasher =
+            &mut crate::hasher::HashProvider::<crate::hasher::PrfHasher>::new(&mut buf_boxed);
+        let pbkdf_key = hasher
+            .pbkdf2_gen("password", "salt", &PBKDF_ROUNDS)
+            .unwrap();
+        let pbkdf_key_hex = hex::encode(*pbkdf_key);
+
+        let config = EncrypterConfig::with_algorithm(
+            pbkdf_key_hex,
+            super::aes::AeadAlgorithm::ChaCha20Poly1305,
+        )
+        .unwrap();
+
+        let mut provider = AesEncryptionProvide::new();
+        let mut enc = super::Encrypter::<AesEncryptionProvide>::new(config);
+
+        // Two calls on the same `Encrypter`, same plaintext and AAD: under ChaCha20Poly1305 a
+        // reused (key, nonce) pair would leak `plain1 XOR plain2`, so this only stays safe because
+        // each call embeds its own freshly generated nonce instead of reusing one stored on the
+        // config.
+        let plain_text = "secret nuke codes";
+        let first = enc.encrypt(plain_text, b"record-42", &mut provider);
+        let second = enc.encrypt(plain_text, b"record-42", &mut provider);
+
+        assert_ne!(first, second);
+        assert_eq!(enc.decrypt(&first, b"record-42").unwrap(), plain_text);
+        assert_eq!(enc.decrypt(&second, b"record-42").unwrap(), plain_text);
+    }
 }
 
 pub struct EncrypterConfig {
@@ -132,24 +281,62 @@ pub struct EncrypterConfig {
 }
 
 impl EncrypterConfig {
-    pub fn new(hash_key: String) -> Self {
-        let key = Aes256GcmSiv::generate_key(&mut OsRng);
-        let cipher = Aes256GcmSiv::new(&key);
-
-        // Generate nonce
-        let mut bytes = hash_key.as_bytes();
-        let mut short_nonce = [0u8; 12];
-        bytes
-            .read_exact(&mut short_nonce)
-            .expect("Nonce is too short");
-        let nonce: &GenericArray<u8, cipher::consts::U12> = Nonce::from_slice(&short_nonce[..]); // 96-bits; unique per message
-
-        let cipher = AesCipher {
-            cipher,
-            nonce: *nonce,
+    pub fn new(hash_key: String) -> crate::error::Result<Self> {
+        Self::with_algorithm(hash_key, AeadAlgorithm::default())
+    }
+
+    pub fn with_algorithm(
+        hash_key: String,
+        algorithm: AeadAlgorithm,
+    ) -> crate::error::Result<Self> {
+        // `hash_key` is the hex-encoded PBKDF2 output; it's the only thing that makes the
+        // ciphertext decryptable again, so it must drive the actual cipher key rather than
+        // being thrown away on a random one.
+        let mut derived_key = hex::decode(&hash_key).map_err(|err| {
+            crate::DefaultError::ErrorMessage(format!(
+                "[{}] hash_key must be the hex-encoded pbkdf2_gen output: {}.",
+                env!("CARGO_CRATE_NAME"),
+                err
+            ))
+        })?;
+
+        // `build_cipher` slices `derived_key` down to the algorithm's key length without further
+        // checks, so a too-short hash_key must be rejected here rather than panic below.
+        let required_len = match algorithm {
+            AeadAlgorithm::Aes256GcmSiv | AeadAlgorithm::ChaCha20Poly1305 => 32,
+            AeadAlgorithm::Aes128GcmSiv => 16,
         };
+        if derived_key.len() < required_len {
+            derived_key.zeroize();
+            return Err(crate::DefaultError::ErrorMessage(format!(
+                "[{}] hash_key is too short for {:?}: need at least {} bytes, got {}.",
+                env!("CARGO_CRATE_NAME"),
+                algorithm,
+                required_len,
+                derived_key.len()
+            )));
+        }
+
+        let cipher = aes::build_cipher(&derived_key, algorithm);
+
+        // `derived_key` is the raw symmetric key; wipe it now that the cipher holds its own copy.
+        derived_key.zeroize();
+
+        // No nonce is generated or stored here: `Encrypter::encrypt` can be called more than once
+        // on the same config (it takes `&mut self`, not `self`), so a nonce fixed at construction
+        // time would be reused across calls. `AesEncryptionProvide::perform_encryption` generates
+        // a fresh one per call instead and embeds it in the ciphertext.
+        let cipher = AesCipher { cipher };
+
+        Ok(Self { hash_key, cipher })
+    }
+}
 
-        Self { hash_key, cipher }
+/// `hash_key` is the hex-encoded PBKDF2 output that the cipher key is derived from, so it must
+/// not linger in freed memory once the config is dropped.
+impl Drop for EncrypterConfig {
+    fn drop(&mut self) {
+        self.hash_key.zeroize();
     }
 }
 
@@ -169,17 +356,662 @@ mod tests {
         let pbkdf_key = hasher
             .pbkdf2_gen("password", "salt", &PBKDF_ROUNDS)
             .unwrap();
-        let pbkdf_key_hex = hex::encode(pbkdf_key);
+        let pbkdf_key_hex = hex::encode(*pbkdf_key);
 
-        let _config = EncrypterConfig::new(pbkdf_key_hex);
+        let _config = EncrypterConfig::new(pbkdf_key_hex).unwrap();
     }
 }
 
 pub mod aes {
     use super::*;
 
+    /// Object-safe abstraction over an AEAD so `Encrypter` isn't locked to one algorithm.
+    ///
+    /// Implementors only need to forward to their own `AeadInPlace` impl; the `*_to_vec`
+    /// convenience methods are derived from `encrypt_in_place`/`decrypt_in_place`.
+    pub trait AeadModule {
+        fn encrypt_in_place(
+            &self,
+            nonce: &GenericArray<u8, cipher::consts::U12>,
+            aad: &[u8],
+            buffer: &mut dyn Buffer,
+        ) -> aes_gcm_siv::aead::Result<()>;
+
+        fn decrypt_in_place(
+            &self,
+            nonce: &GenericArray<u8, cipher::consts::U12>,
+            aad: &[u8],
+            buffer: &mut dyn Buffer,
+        ) -> aes_gcm_siv::aead::Result<()>;
+
+        fn encrypt_to_vec(
+            &self,
+            nonce: &GenericArray<u8, cipher::consts::U12>,
+            aad: &[u8],
+            plain_text: &[u8],
+        ) -> aes_gcm_siv::aead::Result<Vec<u8>> {
+            let mut buffer = AesVecBuffer::<()>::new();
+            buffer
+                .extend_from_slice(plain_text)
+                .expect("buffer has room for plaintext");
+            self.encrypt_in_place(nonce, aad, &mut buffer)?;
+            Ok(buffer.inner().to_vec())
+        }
+
+        fn decrypt_to_vec(
+            &self,
+            nonce: &GenericArray<u8, cipher::consts::U12>,
+            aad: &[u8],
+            cipher_text: &[u8],
+        ) -> aes_gcm_siv::aead::Result<Vec<u8>> {
+            let mut buffer = AesVecBuffer::<()>::new();
+            buffer
+                .extend_from_slice(cipher_text)
+                .expect("buffer has room for ciphertext");
+            self.decrypt_in_place(nonce, aad, &mut buffer)?;
+            Ok(buffer.inner().to_vec())
+        }
+    }
+
+    macro_rules! impl_aead_module {
+        ($cipher:ty) => {
+            impl AeadModule for $cipher {
+                fn encrypt_in_place(
+                    &self,
+                    nonce: &GenericArray<u8, cipher::consts::U12>,
+                    aad: &[u8],
+                    buffer: &mut dyn Buffer,
+                ) -> aes_gcm_siv::aead::Result<()> {
+                    AeadInPlace::encrypt_in_place(self, nonce, aad, buffer)
+                }
+
+                fn decrypt_in_place(
+                    &self,
+                    nonce: &GenericArray<u8, cipher::consts::U12>,
+                    aad: &[u8],
+                    buffer: &mut dyn Buffer,
+                ) -> aes_gcm_siv::aead::Result<()> {
+                    AeadInPlace::decrypt_in_place(self, nonce, aad, buffer)
+                }
+            }
+        };
+    }
+
+    impl_aead_module!(Aes256GcmSiv);
+    impl_aead_module!(Aes128GcmSiv);
+    impl_aead_module!(ChaCha20Poly1305);
+
+    /// Selects which `AeadModule` an `EncrypterConfig` builds its cipher from.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AeadAlgorithm {
+        Aes256GcmSiv,
+        Aes128GcmSiv,
+        ChaCha20Poly1305,
+    }
+
+    impl Default for AeadAlgorithm {
+        fn default() -> Self {
+            Self::Aes256GcmSiv
+        }
+    }
+
+    impl AeadAlgorithm {
+        /// Single-byte tag identifying the algorithm inside a ciphertext envelope.
+        pub fn tag(self) -> u8 {
+            match self {
+                Self::Aes256GcmSiv => 0,
+                Self::Aes128GcmSiv => 1,
+                Self::ChaCha20Poly1305 => 2,
+            }
+        }
+
+        pub fn from_tag(tag: u8) -> crate::error::Result<Self> {
+            match tag {
+                0 => Ok(Self::Aes256GcmSiv),
+                1 => Ok(Self::Aes128GcmSiv),
+                2 => Ok(Self::ChaCha20Poly1305),
+                _ => Err(crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Unknown AEAD algorithm tag {}.",
+                    env!("CARGO_CRATE_NAME"),
+                    tag
+                ))),
+            }
+        }
+    }
+
+    /// Builds the boxed `AeadModule` for `algorithm` from a PBKDF2-derived key, taking only the
+    /// key-length prefix of `key` each algorithm actually needs.
+    pub fn build_cipher(key: &[u8], algorithm: AeadAlgorithm) -> Box<dyn AeadModule> {
+        match algorithm {
+            AeadAlgorithm::Aes256GcmSiv => {
+                Box::new(Aes256GcmSiv::new(GenericArray::from_slice(&key[..32])))
+            }
+            AeadAlgorithm::Aes128GcmSiv => {
+                Box::new(Aes128GcmSiv::new(GenericArray::from_slice(&key[..16])))
+            }
+            AeadAlgorithm::ChaCha20Poly1305 => {
+                Box::new(ChaCha20Poly1305::new(GenericArray::from_slice(&key[..32])))
+            }
+        }
+    }
+
     pub struct AesCipher {
-        pub cipher: AesGcmSiv<::aes::Aes256>,
-        pub nonce: GenericArray<u8, cipher::consts::U12>,
+        pub cipher: Box<dyn AeadModule>,
+    }
+}
+
+/// Self-describing ciphertexts: a fresh salt and nonce are generated per message and travel
+/// alongside the algorithm tag and PBKDF2 rounds, so a ciphertext never needs out-of-band
+/// parameters to be decrypted later.
+pub mod envelope {
+    use super::*;
+    use crate::hasher::Hashable;
+    use std::num::NonZeroU32;
+
+    const VERSION: u8 = 1;
+    const SALT_LEN: usize = 16;
+    const NONCE_LEN: usize = 12;
+    const HEADER_LEN: usize = 1 + 1 + 4 + SALT_LEN + NONCE_LEN;
+
+    struct Envelope {
+        algorithm: AeadAlgorithm,
+        rounds: NonZeroU32,
+        salt: [u8; SALT_LEN],
+        nonce: GenericArray<u8, cipher::consts::U12>,
+        cipher_text: Vec<u8>,
+    }
+
+    impl Envelope {
+        fn to_bytes(&self) -> Vec<u8> {
+            let mut bytes = Vec::with_capacity(HEADER_LEN + self.cipher_text.len());
+            bytes.push(VERSION);
+            bytes.push(self.algorithm.tag());
+            bytes.extend_from_slice(&self.rounds.get().to_be_bytes());
+            bytes.extend_from_slice(&self.salt);
+            bytes.extend_from_slice(&self.nonce);
+            bytes.extend_from_slice(&self.cipher_text);
+            bytes
+        }
+
+        fn from_bytes(bytes: &[u8]) -> crate::error::Result<Self> {
+            if bytes.len() < HEADER_LEN {
+                return Err(crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Ciphertext envelope is too short.",
+                    env!("CARGO_CRATE_NAME")
+                )));
+            }
+
+            let version = bytes[0];
+            if version != VERSION {
+                return Err(crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Unsupported ciphertext envelope version {}.",
+                    env!("CARGO_CRATE_NAME"),
+                    version
+                )));
+            }
+
+            let algorithm = AeadAlgorithm::from_tag(bytes[1])?;
+
+            let mut rounds_bytes = [0u8; 4];
+            rounds_bytes.copy_from_slice(&bytes[2..6]);
+            let rounds = NonZeroU32::new(u32::from_be_bytes(rounds_bytes)).ok_or_else(|| {
+                crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Ciphertext envelope has zero PBKDF2 rounds.",
+                    env!("CARGO_CRATE_NAME")
+                ))
+            })?;
+
+            let mut salt = [0u8; SALT_LEN];
+            salt.copy_from_slice(&bytes[6..6 + SALT_LEN]);
+
+            let nonce_start = 6 + SALT_LEN;
+            let nonce = *GenericArray::from_slice(&bytes[nonce_start..nonce_start + NONCE_LEN]);
+
+            let cipher_text = bytes[nonce_start + NONCE_LEN..].to_vec();
+
+            Ok(Self {
+                algorithm,
+                rounds,
+                salt,
+                nonce,
+                cipher_text,
+            })
+        }
+    }
+
+    fn derive_key(
+        password: &str,
+        salt: &[u8; SALT_LEN],
+        rounds: NonZeroU32,
+    ) -> zeroize::Zeroizing<[u8; 32]> {
+        let mut key_buf = Box::new([0u8; 32]);
+        let hasher =
+            &mut crate::hasher::HashProvider::<crate::hasher::PrfHasher>::new(&mut key_buf);
+
+        // `pbkdf2_gen` already returns the key `Zeroizing`-wrapped; no extra wrapping needed here.
+        hasher
+            .pbkdf2_gen(password, &hex::encode(salt), &rounds.get())
+            .expect("HMAC can be initialized with any key length")
+    }
+
+    /// Encrypts `plain_text` into a hex-encoded envelope. A fresh random salt and nonce are
+    /// generated for this message; the key is derived from `password` via PBKDF2 with `rounds`
+    /// iterations so `open` can later re-derive it from the embedded salt alone.
+    pub fn seal(
+        password: &str,
+        plain_text: &str,
+        aad: &[u8],
+        algorithm: AeadAlgorithm,
+        rounds: u32,
+    ) -> crate::error::Result<String> {
+        let rounds = NonZeroU32::new(rounds).ok_or_else(|| {
+            crate::DefaultError::ErrorMessage(format!(
+                "[{}] PBKDF2 rounds must be non-zero.",
+                env!("CARGO_CRATE_NAME")
+            ))
+        })?;
+
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let derived_key = derive_key(password, &salt, rounds);
+        let cipher = aes::build_cipher(&derived_key[..], algorithm);
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce: GenericArray<u8, cipher::consts::U12> = *Nonce::from_slice(&nonce_bytes);
+
+        let cipher_text = cipher
+            .encrypt_to_vec(&nonce, aad, plain_text.as_bytes())
+            .map_err(|err| {
+                crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Failed to encrypt due to {}.",
+                    env!("CARGO_CRATE_NAME"),
+                    err
+                ))
+            })?;
+
+        let envelope = Envelope {
+            algorithm,
+            rounds,
+            salt,
+            nonce,
+            cipher_text,
+        };
+
+        Ok(hex::encode(envelope.to_bytes()))
+    }
+
+    /// Parses a `seal`-produced envelope, re-derives the key from `password` using the embedded
+    /// salt/rounds, and recovers the plaintext. Fails authentication if `aad` doesn't match what
+    /// the envelope was sealed with.
+    pub fn open(password: &str, envelope_hex: &str, aad: &[u8]) -> crate::error::Result<String> {
+        let bytes = hex::decode(envelope_hex).map_err(|err| {
+            crate::DefaultError::ErrorMessage(format!(
+                "[{}] Failed to hex-decode ciphertext envelope due to {}.",
+                env!("CARGO_CRATE_NAME"),
+                err
+            ))
+        })?;
+
+        let envelope = Envelope::from_bytes(&bytes)?;
+        let derived_key = derive_key(password, &envelope.salt, envelope.rounds);
+        let cipher = aes::build_cipher(&derived_key[..], envelope.algorithm);
+
+        let plain_text = cipher
+            .decrypt_to_vec(&envelope.nonce, aad, &envelope.cipher_text)
+            .map_err(|err| {
+                crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Failed to decrypt due to {}.",
+                    env!("CARGO_CRATE_NAME"),
+                    err
+                ))
+            })?;
+
+        String::from_utf8(plain_text).map_err(|err| {
+            crate::DefaultError::ErrorMessage(format!(
+                "[{}] Decrypted plaintext is not valid UTF-8: {}.",
+                env!("CARGO_CRATE_NAME"),
+                err
+            ))
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_seal_open_round_trip() {
+            let plain_text = "secret nuke codes";
+            let envelope = seal(
+                "password",
+                plain_text,
+                b"record-42",
+                AeadAlgorithm::default(),
+                2,
+            )
+            .unwrap();
+
+            let recovered = open("password", &envelope, b"record-42").unwrap();
+
+            assert_eq!(recovered, plain_text);
+        }
+
+        #[test]
+        fn test_open_rejects_wrong_password() {
+            let envelope = seal(
+                "password",
+                "secret nuke codes",
+                b"",
+                AeadAlgorithm::default(),
+                2,
+            )
+            .unwrap();
+
+            assert!(open("hunter2", &envelope, b"").is_err());
+        }
+    }
+}
+
+/// Chunked encryption/decryption of a `Read` source into a `Write` sink, so large payloads
+/// never need to be buffered whole in memory.
+///
+/// # Single-nonce-per-stream invariant
+///
+/// Every chunk derives its own sub-nonce from `base_nonce` (a chunk counter in the low 4 bytes,
+/// with the low bit reserved as a last-chunk flag so a truncated stream can't be mistaken for a
+/// complete one), following the STREAM construction. `base_nonce` itself must therefore be
+/// unique per stream, exactly like a regular AEAD nonce: reusing it across two streams reuses
+/// every chunk nonce pairwise between them.
+pub mod stream {
+    use super::aes::AeadModule;
+    use super::*;
+    use std::io::{Read, Write};
+
+    /// Plaintext chunk size used unless a caller picks their own.
+    pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// AEAD tag length appended to every chunk's ciphertext.
+    const TAG_LEN: usize = 16;
+
+    fn chunk_nonce(
+        base_nonce: &GenericArray<u8, cipher::consts::U12>,
+        index: u32,
+        is_last: bool,
+    ) -> GenericArray<u8, cipher::consts::U12> {
+        let mut nonce = *base_nonce;
+        let counter = (index << 1) | (is_last as u32);
+        nonce[8..12].copy_from_slice(&counter.to_be_bytes());
+        nonce
+    }
+
+    fn io_err(err: std::io::Error) -> crate::DefaultError {
+        crate::DefaultError::ErrorMessage(format!(
+            "[{}] Stream I/O failed due to {}.",
+            env!("CARGO_CRATE_NAME"),
+            err
+        ))
+    }
+
+    fn aead_err(err: aes_gcm_siv::aead::Error) -> crate::DefaultError {
+        crate::DefaultError::ErrorMessage(format!(
+            "[{}] Failed to authenticate stream chunk due to {}.",
+            env!("CARGO_CRATE_NAME"),
+            err
+        ))
+    }
+
+    /// Reads up to `buf.len()` bytes, looping until `buf` is full or the source is exhausted.
+    fn fill_buffer<R: Read>(source: &mut R, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let n = source.read(&mut buf[filled..])?;
+            if n == 0 {
+                break;
+            }
+            filled += n;
+        }
+        Ok(filled)
+    }
+
+    fn write_chunk<W: Write>(
+        cipher: &dyn AeadModule,
+        base_nonce: &GenericArray<u8, cipher::consts::U12>,
+        aad: &[u8],
+        index: u32,
+        is_last: bool,
+        plain_text: &[u8],
+        sink: &mut W,
+    ) -> crate::error::Result<()> {
+        let nonce = chunk_nonce(base_nonce, index, is_last);
+        let cipher_text = cipher
+            .encrypt_to_vec(&nonce, aad, plain_text)
+            .map_err(aead_err)?;
+        debug_assert_eq!(cipher_text.len(), plain_text.len() + TAG_LEN);
+
+        sink.write_all(&(cipher_text.len() as u32).to_be_bytes())
+            .map_err(io_err)?;
+        sink.write_all(&cipher_text).map_err(io_err)?;
+
+        Ok(())
+    }
+
+    /// Reads the next chunk's length prefix, returning `None` at a clean end-of-stream.
+    fn read_chunk_header<R: Read>(source: &mut R) -> crate::error::Result<Option<u32>> {
+        let mut len_bytes = [0u8; 4];
+        let read = fill_buffer(source, &mut len_bytes).map_err(io_err)?;
+
+        if read == 0 {
+            return Ok(None);
+        }
+        if read < len_bytes.len() {
+            return Err(crate::DefaultError::ErrorMessage(format!(
+                "[{}] Stream ended mid chunk-length header.",
+                env!("CARGO_CRATE_NAME")
+            )));
+        }
+
+        Ok(Some(u32::from_be_bytes(len_bytes)))
+    }
+
+    /// Encrypts `source` in `chunk_size`-byte plaintext blocks, writing each chunk's
+    /// length-prefixed ciphertext-plus-tag to `sink` as it's produced.
+    pub fn encrypt<R: Read, W: Write>(
+        cipher: &dyn AeadModule,
+        base_nonce: &GenericArray<u8, cipher::consts::U12>,
+        aad: &[u8],
+        chunk_size: usize,
+        mut source: R,
+        mut sink: W,
+    ) -> crate::error::Result<()> {
+        let mut current = vec![0u8; chunk_size];
+        let mut current_len = fill_buffer(&mut source, &mut current).map_err(io_err)?;
+        let mut index: u32 = 0;
+
+        loop {
+            if current_len < chunk_size {
+                return write_chunk(
+                    cipher,
+                    base_nonce,
+                    aad,
+                    index,
+                    true,
+                    &current[..current_len],
+                    &mut sink,
+                );
+            }
+
+            let mut next = vec![0u8; chunk_size];
+            let next_len = fill_buffer(&mut source, &mut next).map_err(io_err)?;
+
+            if next_len == 0 {
+                return write_chunk(cipher, base_nonce, aad, index, true, &current, &mut sink);
+            }
+
+            write_chunk(cipher, base_nonce, aad, index, false, &current, &mut sink)?;
+
+            index += 1;
+            current = next;
+            current_len = next_len;
+        }
+    }
+
+    /// Decrypts a `stream::encrypt`-produced sink, writing recovered plaintext to `sink` as
+    /// each chunk is authenticated. Fails if any chunk's tag doesn't verify, or if the stream
+    /// is truncated before its last-chunk-flagged frame.
+    ///
+    /// `max_chunk_len` caps how large a single chunk's ciphertext-plus-tag is allowed to be
+    /// before it's authenticated — it must be at least `chunk_size + TAG_LEN` from the matching
+    /// `encrypt` call (pass `DEFAULT_CHUNK_SIZE + 16` for streams encrypted with the default).
+    /// Without this bound, an attacker-controlled length prefix could force an arbitrarily large
+    /// allocation before a single byte of the frame has been verified.
+    pub fn decrypt<R: Read, W: Write>(
+        cipher: &dyn AeadModule,
+        base_nonce: &GenericArray<u8, cipher::consts::U12>,
+        aad: &[u8],
+        max_chunk_len: usize,
+        mut source: R,
+        mut sink: W,
+    ) -> crate::error::Result<()> {
+        let mut index: u32 = 0;
+        let mut header = read_chunk_header(&mut source)?;
+
+        loop {
+            let len = header.ok_or_else(|| {
+                crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Stream ended without a final chunk.",
+                    env!("CARGO_CRATE_NAME")
+                ))
+            })?;
+
+            if len as usize > max_chunk_len {
+                return Err(crate::DefaultError::ErrorMessage(format!(
+                    "[{}] Chunk length {} exceeds max_chunk_len {}.",
+                    env!("CARGO_CRATE_NAME"),
+                    len,
+                    max_chunk_len
+                )));
+            }
+
+            let mut cipher_text = vec![0u8; len as usize];
+            source.read_exact(&mut cipher_text).map_err(io_err)?;
+
+            let next_header = read_chunk_header(&mut source)?;
+            let is_last = next_header.is_none();
+
+            let nonce = chunk_nonce(base_nonce, index, is_last);
+            let plain_text = cipher
+                .decrypt_to_vec(&nonce, aad, &cipher_text)
+                .map_err(aead_err)?;
+            sink.write_all(&plain_text).map_err(io_err)?;
+
+            if is_last {
+                return Ok(());
+            }
+
+            index += 1;
+            header = next_header;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::hasher::Hashable;
+        use std::io::Cursor;
+
+        fn test_cipher() -> Box<dyn AeadModule> {
+            const PBKDF_ROUNDS: u32 = 2;
+            let mut buf = Box::new([0u8; 32]);
+            let hasher =
+                &mut crate::hasher::HashProvider::<crate::hasher::PrfHasher>::new(&mut buf);
+            let key = hasher.pbkdf2_gen("password", "salt", &PBKDF_ROUNDS).unwrap();
+
+            super::super::aes::build_cipher(&key[..], super::super::aes::AeadAlgorithm::default())
+        }
+
+        #[test]
+        fn test_stream_round_trip_multiple_chunks() {
+            let cipher = test_cipher();
+            let base_nonce = GenericArray::from_slice(&[7u8; 12]);
+            let plain_text: Vec<u8> = (0..250u32).flat_map(|n| n.to_be_bytes()).collect();
+
+            let mut cipher_text = Vec::new();
+            encrypt(
+                cipher.as_ref(),
+                base_nonce,
+                b"aad",
+                16,
+                Cursor::new(&plain_text),
+                &mut cipher_text,
+            )
+            .unwrap();
+
+            let mut recovered = Vec::new();
+            decrypt(
+                cipher.as_ref(),
+                base_nonce,
+                b"aad",
+                16 + TAG_LEN,
+                Cursor::new(&cipher_text),
+                &mut recovered,
+            )
+            .unwrap();
+
+            assert_eq!(recovered, plain_text);
+        }
+
+        #[test]
+        fn test_stream_rejects_truncated_tail() {
+            let cipher = test_cipher();
+            let base_nonce = GenericArray::from_slice(&[7u8; 12]);
+            let plain_text: Vec<u8> = (0..250u32).flat_map(|n| n.to_be_bytes()).collect();
+
+            let mut cipher_text = Vec::new();
+            encrypt(
+                cipher.as_ref(),
+                base_nonce,
+                b"aad",
+                16,
+                Cursor::new(&plain_text),
+                &mut cipher_text,
+            )
+            .unwrap();
+
+            cipher_text.truncate(cipher_text.len() - 20);
+
+            let mut recovered = Vec::new();
+            assert!(decrypt(
+                cipher.as_ref(),
+                base_nonce,
+                b"aad",
+                16 + TAG_LEN,
+                Cursor::new(&cipher_text),
+                &mut recovered,
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn test_stream_decrypt_rejects_oversized_chunk_length() {
+            let cipher = test_cipher();
+            let base_nonce = GenericArray::from_slice(&[7u8; 12]);
+
+            // A length prefix far beyond any real chunk must be rejected before allocating.
+            let mut malicious = (u32::MAX - 1).to_be_bytes().to_vec();
+            malicious.extend_from_slice(&[0u8; 8]);
+
+            let mut recovered = Vec::new();
+            assert!(decrypt(
+                cipher.as_ref(),
+                base_nonce,
+                b"aad",
+                DEFAULT_CHUNK_SIZE + TAG_LEN,
+                Cursor::new(&malicious),
+                &mut recovered,
+            )
+            .is_err());
+        }
     }
 }
@@ -3,29 +3,33 @@ use hmac::{digest::core_api::CoreWrapper, EagerHash, Hmac, HmacCore};
 use pbkdf2::pbkdf2;
 use sha2::Sha512;
 use std::{fmt::Debug, marker::PhantomData};
+use zeroize::Zeroize;
 
 pub type PrfHasher = Sha512;
-pub const KEY_BUFF_SIZE: usize = 20;
+/// AES-256 needs a 32-byte key, so the PBKDF2 output is sized to match it directly.
+pub const KEY_BUFF_SIZE: usize = 32;
 
-pub trait Hashable<H> {
-    type KeyBuf;
+pub trait Hashable<H, const N: usize = KEY_BUFF_SIZE> {
+    type KeyBuf: Zeroize;
 
+    /// Returns the derived key wrapped in `Zeroizing` so callers can't forget to scrub their own
+    /// copy of it, the same way `HashProvider`'s internal buffer is already scrubbed on drop.
     fn pbkdf2_gen(
         &mut self,
         password: &str,
         salt: &str,
         rounds: &u32,
-    ) -> error::Result<Self::KeyBuf>;
+    ) -> error::Result<zeroize::Zeroizing<Self::KeyBuf>>;
 }
 
 #[derive(Debug)]
-pub struct HashProvider<'a, H> {
+pub struct HashProvider<'a, H, const N: usize = KEY_BUFF_SIZE> {
     _hasher: PhantomData<H>,
-    key: &'a mut Box<[u8; KEY_BUFF_SIZE]>,
+    key: &'a mut Box<[u8; N]>,
 }
 
-impl<'a, H> HashProvider<'a, H> {
-    pub fn new(buf: &'a mut Box<[u8; KEY_BUFF_SIZE]>) -> Self {
+impl<'a, H, const N: usize> HashProvider<'a, H, N> {
+    pub fn new(buf: &'a mut Box<[u8; N]>) -> Self {
         Self {
             _hasher: PhantomData,
             key: buf,
@@ -33,20 +37,27 @@ impl<'a, H> HashProvider<'a, H> {
     }
 }
 
-impl<'a, H> Hashable<H> for HashProvider<'a, H>
+/// The derived key buffer is secret-bearing and must not linger in freed memory.
+impl<'a, H, const N: usize> Drop for HashProvider<'a, H, N> {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl<'a, H, const N: usize> Hashable<H, N> for HashProvider<'a, H, N>
 where
     CoreWrapper<HmacCore<H>>: hmac::KeyInit,
     H: hmac::EagerHash,
     <H as EagerHash>::Core: Sync,
 {
-    type KeyBuf = [u8; KEY_BUFF_SIZE];
+    type KeyBuf = [u8; N];
 
     fn pbkdf2_gen(
         &mut self,
         password: &str,
         salt: &str,
         rounds: &u32,
-    ) -> error::Result<Self::KeyBuf>
+    ) -> error::Result<zeroize::Zeroizing<Self::KeyBuf>>
 where {
         pbkdf2::<Hmac<H>>(
             &password.to_string().as_bytes(),
@@ -57,7 +68,7 @@ where {
         )
         .expect("HMAC can be initialized with any key length");
 
-        Ok(*self.key.clone())
+        Ok(zeroize::Zeroizing::new(*self.key.clone()))
     }
 }
 
@@ -83,8 +94,8 @@ mod tests {
         // let pbkdf_key_hex = hex::encode(pbkdf_key);
 
         assert_eq!(
-            &pbkdf_key,
-            &hex!("e1d9c16aa681708a45f5c7c4e215ceb66e011a2e")
+            *pbkdf_key,
+            hex!("e1d9c16aa681708a45f5c7c4e215ceb66e011a2e9f0040713f18aefdb866d53c")
         );
     }
 }